@@ -29,6 +29,25 @@ enum Direction {
 enum ToriEvent {
     Quit,
     Move(Direction),
+    /// Switch from normal to insert mode.
+    EnterInsert,
+    /// Switch from insert back to normal mode.
+    LeaveInsert,
+    /// Insert a single character at the cursor.
+    InsertChar(char),
+    /// Split the current line at the cursor.
+    Newline,
+    /// Delete the character before the cursor, joining lines if needed.
+    Backspace,
+    /// Write the buffer back to disk.
+    Save,
+}
+
+/// Editing mode of Tori. Gates which key table [handle_input] consults.
+#[derive(Clone, Debug, Copy, Hash, PartialEq, Eq)]
+enum Mode {
+    Normal,
+    Insert,
 }
 
 struct Config {
@@ -51,6 +70,18 @@ fn default_keymap() -> KeyMap {
         ToriEvent::Quit,
     );
 
+    // Enter insert mode.
+    keymap.insert(
+        KeyEvent::new(KeyCode::Char('i'), KeyModifiers::empty()),
+        ToriEvent::EnterInsert,
+    );
+
+    // Write the buffer back to disk.
+    keymap.insert(
+        KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+        ToriEvent::Save,
+    );
+
     // Movement with arrow keys
     keymap.insert(
         KeyEvent::new(KeyCode::Left, KeyModifiers::empty()),
@@ -72,6 +103,30 @@ fn default_keymap() -> KeyMap {
     keymap
 }
 
+/// The key table consulted while in insert mode. Printable characters that are
+/// not bound here are turned into [ToriEvent::InsertChar] by [Tori::handle_input].
+fn insert_keymap() -> KeyMap {
+    let mut keymap = HashMap::new();
+    keymap.insert(
+        KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()),
+        ToriEvent::LeaveInsert,
+    );
+    keymap.insert(
+        KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()),
+        ToriEvent::Newline,
+    );
+    keymap.insert(
+        KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty()),
+        ToriEvent::Backspace,
+    );
+    keymap.insert(
+        KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+        ToriEvent::Save,
+    );
+
+    keymap
+}
+
 // A buffer that is able to hold textual content.
 struct FileBuffer {
     /// Flag indicating if the content has been modified since last save.
@@ -138,8 +193,12 @@ impl FileBuffer {
 struct Tori {
     /// Flag indicating that Tori should quit on the next update.
     should_quit: bool,
-    /// Bindings between [KeyEvent]s and [ToriEvent]s.
+    /// Bindings between [KeyEvent]s and [ToriEvent]s in normal mode.
     keymap: KeyMap,
+    /// Bindings between [KeyEvent]s and [ToriEvent]s in insert mode.
+    insert_keymap: KeyMap,
+    /// Current editing mode.
+    mode: Mode,
     /// Editor configuration struct.
     config: Config,
     /// Active buffer.
@@ -163,6 +222,8 @@ impl Tori {
             rows,
             config: Config::default(),
             keymap: default_keymap(),
+            insert_keymap: insert_keymap(),
+            mode: Mode::Normal,
             buffer,
             screen_rows: 0,
             screen_columns: 0,
@@ -255,6 +316,62 @@ impl Tori {
                 }
                 self.maintain_scroll();
             }
+            ToriEvent::EnterInsert => self.mode = Mode::Insert,
+            ToriEvent::LeaveInsert => self.mode = Mode::Normal,
+            ToriEvent::InsertChar(ch) => {
+                let cy = usize::from(self.buffer.cursor_y);
+                let cx = usize::from(self.buffer.cursor_x);
+                if let Some(line) = self.buffer.content.get_mut(cy) {
+                    line.insert(cx, ch);
+                    self.buffer.cursor_x += 1;
+                    self.buffer.desired_cursor_x = self.buffer.cursor_x;
+                    self.buffer.is_modified = true;
+                    self.maintain_scroll();
+                }
+            }
+            ToriEvent::Newline => {
+                let cy = usize::from(self.buffer.cursor_y);
+                let cx = usize::from(self.buffer.cursor_x);
+                if let Some(line) = self.buffer.content.get_mut(cy) {
+                    // Split the current line at the cursor, carrying the tail
+                    // onto a fresh line below.
+                    let tail = line.split_off(cx);
+                    self.buffer.content.insert(cy + 1, tail);
+                    self.buffer.cursor_y += 1;
+                    self.buffer.cursor_x = 0;
+                    self.buffer.desired_cursor_x = 0;
+                    self.buffer.is_modified = true;
+                    self.maintain_scroll();
+                }
+            }
+            ToriEvent::Backspace => {
+                let cy = usize::from(self.buffer.cursor_y);
+                let cx = usize::from(self.buffer.cursor_x);
+                if cx > 0 {
+                    // Remove the character before the cursor.
+                    self.buffer.content[cy].remove(cx - 1);
+                    self.buffer.cursor_x -= 1;
+                    self.buffer.desired_cursor_x = self.buffer.cursor_x;
+                    self.buffer.is_modified = true;
+                    self.maintain_scroll();
+                } else if cy > 0 {
+                    // Join this line onto the end of the previous one.
+                    let line = self.buffer.content.remove(cy);
+                    let prev_width = self.buffer.line_width(cy - 1);
+                    self.buffer.content[cy - 1].push_str(&line);
+                    self.buffer.cursor_y -= 1;
+                    self.buffer.cursor_x = prev_width;
+                    self.buffer.desired_cursor_x = prev_width;
+                    self.buffer.is_modified = true;
+                    self.maintain_scroll();
+                }
+            }
+            ToriEvent::Save => {
+                let contents = self.buffer.content.join("\n");
+                if std::fs::write(&self.buffer.path, contents).is_ok() {
+                    self.buffer.is_modified = false;
+                }
+            }
         }
     }
 
@@ -271,9 +388,18 @@ impl Tori {
                     return Ok(());
                 }
 
-                // Lookup keyboard event in keymap and dispatch it.
-                if let Some(event) = self.keymap.get(&event) {
-                    self.dispatch(*event);
+                // Consult the key table for the current mode and dispatch.
+                let keymap = match self.mode {
+                    Mode::Normal => &self.keymap,
+                    Mode::Insert => &self.insert_keymap,
+                };
+                if let Some(mapped) = keymap.get(&event).copied() {
+                    self.dispatch(mapped);
+                } else if self.mode == Mode::Insert {
+                    // In insert mode, unmapped printable keys become text.
+                    if let KeyCode::Char(ch) = event.code {
+                        self.dispatch(ToriEvent::InsertChar(ch));
+                    }
                 }
             }
             _ => {}